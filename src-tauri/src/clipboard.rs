@@ -0,0 +1,65 @@
+// Clipboard export for formatted QRE report summaries.
+
+use arboard::Clipboard;
+
+/// Sets the system clipboard to `text`. On Linux, X11/Wayland release the
+/// selection as soon as the owning `Clipboard` drops, so the content would
+/// vanish the instant this function returned; `SetExtLinux::wait()` keeps it
+/// alive on a background thread until another application takes ownership.
+#[cfg(target_os = "linux")]
+fn set_clipboard_text(text: String) -> Result<(), String> {
+    use arboard::SetExtLinux;
+
+    std::thread::spawn(move || {
+        if let Ok(mut clipboard) = Clipboard::new() {
+            let _ = clipboard.set().wait().text(text);
+        }
+    });
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_clipboard_text(text: String) -> Result<(), String> {
+    let mut clipboard = Clipboard::new().map_err(|e| e.to_string())?;
+    clipboard.set_text(text).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn copy_report_to_clipboard(report_markdown: String) -> Result<(), String> {
+    set_clipboard_text(report_markdown)
+}
+
+/// Places `report_markdown` on the clipboard and, when the `auto-paste`
+/// feature is enabled, simulates a paste keystroke into whatever application
+/// currently has focus.
+#[tauri::command]
+pub fn export_to_active_app(report_markdown: String) -> Result<(), String> {
+    copy_report_to_clipboard(report_markdown)?;
+
+    #[cfg(feature = "auto-paste")]
+    {
+        paste::simulate_paste().map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "auto-paste")]
+mod paste {
+    use enigo::{Enigo, Key, KeyboardControllable};
+
+    pub fn simulate_paste() -> Result<(), String> {
+        let mut enigo = Enigo::new();
+        let modifier = if cfg!(target_os = "macos") {
+            Key::Meta
+        } else {
+            Key::Control
+        };
+
+        enigo.key_down(modifier);
+        enigo.key_click(Key::Layout('v'));
+        enigo.key_up(modifier);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+// Commands backing the native menu's circuit file operations.
+
+use std::fs;
+
+use tauri::api::dialog::FileDialogBuilder;
+use tauri::Window;
+
+#[tauri::command]
+pub fn open_circuit_dialog(window: Window) {
+    FileDialogBuilder::new()
+        .add_filter("Circuit", &["qasm", "json"])
+        .pick_file(move |path| {
+            let Some(path) = path else {
+                return;
+            };
+
+            match fs::read_to_string(&path) {
+                Ok(content) => {
+                    let _ = window.emit("circuit-loaded", (path.display().to_string(), content));
+                }
+                Err(err) => {
+                    let _ = window.emit("circuit-load-error", err.to_string());
+                }
+            }
+        });
+}
+
+#[tauri::command]
+pub fn save_report(window: Window) {
+    FileDialogBuilder::new()
+        .add_filter("Markdown", &["md"])
+        .save_file(move |path| {
+            if let Some(path) = path {
+                let _ = window.emit("report-save-path-selected", path.display().to_string());
+            }
+        });
+}
+
+/// Shared handler for the "Export as JSON" / "Export as CSV" menu items: the
+/// frontend holds the report data, so this only resolves the destination
+/// path and lets the caller follow up with `write_report_file`.
+pub fn export_report(window: Window, format: &str) {
+    FileDialogBuilder::new()
+        .add_filter(format, &[format])
+        .save_file(move |path| {
+            if let Some(path) = path {
+                let _ = window.emit("export-path-selected", path.display().to_string());
+            }
+        });
+}
+
+/// Writes `content` to `path`, used after a save/export dialog has resolved
+/// a destination and the frontend has the report text to persist.
+#[tauri::command]
+pub fn write_report_file(path: String, content: String) -> Result<(), String> {
+    fs::write(path, content).map_err(|e| e.to_string())
+}
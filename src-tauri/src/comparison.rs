@@ -0,0 +1,76 @@
+// Manages extra windows for side-by-side resource estimate comparisons.
+
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Manager, WindowBuilder, WindowEvent, WindowUrl};
+
+/// Labels of comparison windows currently open, so the app can broadcast a
+/// "refresh" event to all of them when shared parameters change.
+#[derive(Default)]
+pub struct ComparisonWindows(pub Mutex<Vec<String>>);
+
+/// Reports whether a window with `label` is currently open.
+#[tauri::command]
+pub fn get_window_by_label(app: AppHandle, label: String) -> bool {
+    app.get_window(&label).is_some()
+}
+
+/// Focuses `label` if it's already open, otherwise builds it fresh and
+/// records it in `ComparisonWindows`. This ordering matters: looking the
+/// window up first and only building on a genuine miss is what avoids
+/// recreating (and crashing on) a window that already exists.
+#[tauri::command]
+pub fn focus_or_create(app: AppHandle, label: String) -> Result<(), String> {
+    if let Some(window) = app.get_window(&label) {
+        if window.is_minimized().map_err(|e| e.to_string())? {
+            window.unminimize().map_err(|e| e.to_string())?;
+        }
+        window.set_focus().map_err(|e| e.to_string())?;
+        return Ok(());
+    }
+
+    let window = WindowBuilder::new(&app, &label, WindowUrl::App("comparison.html".into()))
+        .title("Resource Estimate Comparison")
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    // Prune the label once the window actually closes, however that
+    // happens (our own code, the OS close button, Cmd+W, ...), so the
+    // tracking list can't accumulate stale entries.
+    let closed_label = label.clone();
+    let app_for_close = app.clone();
+    window.on_window_event(move |event| {
+        if let WindowEvent::Destroyed = event {
+            app_for_close
+                .state::<ComparisonWindows>()
+                .0
+                .lock()
+                .unwrap()
+                .retain(|open_label| open_label != &closed_label);
+        }
+    });
+
+    let mut open_labels = app.state::<ComparisonWindows>().0.lock().unwrap();
+    if !open_labels.contains(&label) {
+        open_labels.push(label);
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn open_comparison_window(app: AppHandle, label: String) -> Result<(), String> {
+    focus_or_create(app, label)
+}
+
+/// Broadcasts a "refresh" event to every open comparison window, for when
+/// shared parameters (hardware model, gate fidelities) change.
+#[tauri::command]
+pub fn refresh_comparison_windows(app: AppHandle) {
+    let labels = app.state::<ComparisonWindows>().0.lock().unwrap().clone();
+    for label in labels {
+        if let Some(window) = app.get_window(&label) {
+            let _ = window.emit("comparison-refresh", ());
+        }
+    }
+}
@@ -0,0 +1,116 @@
+// Background resource estimation with progress streamed to the frontend via
+// events, so the UI can show a live bar instead of blocking on `invoke`.
+
+use serde::Serialize;
+use tauri::Window;
+
+/// A circuit description as received from the frontend. Kept intentionally
+/// small for now; estimation only needs the gate count to report progress.
+#[derive(Debug, serde::Deserialize)]
+pub struct Circuit {
+    pub gates: Vec<String>,
+    pub qubit_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EstimationProgress {
+    pub gates_processed: u32,
+    pub total_gates: u32,
+    pub current_qubit_count: u32,
+    // TODO: placeholder heuristics — `t_count` only matches literal "T"/
+    // "T_dagger" gate names (no synthesis-aware decomposition yet) and
+    // `depth` is just the running gate count, not true circuit depth
+    // (parallel gates on independent qubits aren't collapsed). Replace once
+    // the real estimator lands.
+    pub t_count: u64,
+    pub depth: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EstimationReport {
+    pub qubit_count: u32,
+    // TODO: see placeholder note on `EstimationProgress`.
+    pub t_count: u64,
+    pub depth: u32,
+}
+
+/// Approximate number of progress updates emitted over the course of an
+/// estimation. Emitting on every gate floods the IPC channel for realistic
+/// circuits, so progress is reported roughly every
+/// `total_gates / PROGRESS_UPDATES` gates instead. Because that interval
+/// rounds down and is at least 1, a circuit with fewer gates than this emits
+/// on every gate, and one with up to twice as many can emit nearly twice as
+/// many updates.
+const PROGRESS_UPDATES: u32 = 100;
+
+/// Starts estimating `circuit` on a background task, periodically emitting
+/// `estimation-progress` events as gates are processed and a final
+/// `estimation-complete` event with the full report.
+#[tauri::command]
+pub fn start_estimation(window: Window, init: tauri::State<crate::state::InitState>, circuit: Circuit) -> Result<(), String> {
+    init.ensure_ready()?;
+
+    tauri::async_runtime::spawn(async move {
+        let total_gates = circuit.gates.len() as u32;
+        let interval = progress_interval(total_gates);
+        let mut t_count: u64 = 0;
+        let mut depth: u32 = 0;
+
+        for (processed, gate) in circuit.gates.iter().enumerate() {
+            if gate == "T" || gate == "T_dagger" {
+                t_count += 1;
+            }
+            depth += 1;
+
+            let gates_processed = processed as u32 + 1;
+            let is_last = gates_processed == total_gates;
+            if gates_processed % interval == 0 || is_last {
+                let progress = EstimationProgress {
+                    gates_processed,
+                    total_gates,
+                    current_qubit_count: circuit.qubit_count,
+                    t_count,
+                    depth,
+                };
+                let _ = window.emit("estimation-progress", progress);
+            }
+        }
+
+        let report = EstimationReport {
+            qubit_count: circuit.qubit_count,
+            t_count,
+            depth,
+        };
+        let _ = window.emit("estimation-complete", report);
+    });
+
+    Ok(())
+}
+
+/// How often (in gates) a progress event is emitted for a circuit of
+/// `total_gates` size. Always at least 1, so a circuit smaller than
+/// `PROGRESS_UPDATES` still emits every gate rather than dividing by zero.
+fn progress_interval(total_gates: u32) -> u32 {
+    (total_gates / PROGRESS_UPDATES).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_interval_never_zero_for_small_circuits() {
+        assert_eq!(progress_interval(0), 1);
+        assert_eq!(progress_interval(5), 1);
+    }
+
+    #[test]
+    fn progress_interval_caps_update_count_for_large_circuits() {
+        let total_gates = 1_000_000;
+        let interval = progress_interval(total_gates);
+        let emitted_updates = total_gates / interval;
+
+        assert!(interval > 1);
+        assert!(emitted_updates <= PROGRESS_UPDATES + 1);
+    }
+}
@@ -0,0 +1,137 @@
+// Background estimation job queue backing the system tray. Jobs are enqueued
+// from the frontend and each runs concurrently on its own background task
+// (no serialization between them); the tray title/tooltip is refreshed as
+// they complete.
+
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+
+use crate::estimator::Circuit;
+use crate::tray;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EstimationJob {
+    pub id: u64,
+    pub circuit_label: String,
+    pub done: bool,
+}
+
+#[derive(Default)]
+pub struct JobQueue {
+    jobs: Mutex<Vec<EstimationJob>>,
+    next_id: Mutex<u64>,
+}
+
+impl JobQueue {
+    fn next_id(&self) -> u64 {
+        let mut next_id = self.next_id.lock().unwrap();
+        let id = *next_id;
+        *next_id += 1;
+        id
+    }
+
+    fn running_count(&self) -> usize {
+        self.jobs.lock().unwrap().iter().filter(|job| !job.done).count()
+    }
+
+    pub fn cancel_all(&self) {
+        self.jobs.lock().unwrap().clear();
+    }
+}
+
+#[tauri::command]
+pub fn enqueue_estimation(
+    app: AppHandle,
+    init: tauri::State<crate::state::InitState>,
+    circuit_label: String,
+    circuit: Circuit,
+) -> Result<u64, String> {
+    init.ensure_ready()?;
+
+    let queue = app.state::<JobQueue>();
+    let id = queue.next_id();
+
+    queue.jobs.lock().unwrap().push(EstimationJob {
+        id,
+        circuit_label: circuit_label.clone(),
+        done: false,
+    });
+    tray::update_tray_title(&app, queue.running_count());
+
+    let app_for_task = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let total_gates = circuit.gates.len().max(1) as u64;
+        for _ in 0..total_gates {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+
+        let queue = app_for_task.state::<JobQueue>();
+        // Drop the job once it completes rather than leaving it `done` in
+        // the Vec forever — otherwise a long batch session accumulates
+        // finished jobs without bound. `position` (vs. `find`) doubles as
+        // the "was this cancelled while running?" check: a cancelled job is
+        // already removed from the Vec, so there's nothing to find here.
+        let still_queued = {
+            let mut jobs = queue.jobs.lock().unwrap();
+            match jobs.iter().position(|job| job.id == id) {
+                Some(pos) => {
+                    jobs.remove(pos);
+                    true
+                }
+                None => false,
+            }
+        };
+
+        if still_queued {
+            let _ = app_for_task.emit_all("job-complete", id);
+            tray::notify_job_complete(&app_for_task, &circuit_label);
+        }
+        tray::update_tray_title(&app_for_task, queue.running_count());
+    });
+
+    Ok(id)
+}
+
+#[tauri::command]
+pub fn cancel_job(app: AppHandle, job_id: u64) {
+    let queue = app.state::<JobQueue>();
+    queue.jobs.lock().unwrap().retain(|job| job.id != job_id);
+    tray::update_tray_title(&app, queue.running_count());
+}
+
+#[tauri::command]
+pub fn list_jobs(app: AppHandle) -> Vec<EstimationJob> {
+    app.state::<JobQueue>().jobs.lock().unwrap().clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_id_increments_from_zero() {
+        let queue = JobQueue::default();
+        assert_eq!(queue.next_id(), 0);
+        assert_eq!(queue.next_id(), 1);
+        assert_eq!(queue.next_id(), 2);
+    }
+
+    #[test]
+    fn running_count_ignores_done_jobs() {
+        let queue = JobQueue::default();
+        queue.jobs.lock().unwrap().push(EstimationJob {
+            id: 0,
+            circuit_label: "a".into(),
+            done: false,
+        });
+        queue.jobs.lock().unwrap().push(EstimationJob {
+            id: 1,
+            circuit_label: "b".into(),
+            done: true,
+        });
+
+        assert_eq!(queue.running_count(), 1);
+    }
+}
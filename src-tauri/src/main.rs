@@ -3,26 +3,66 @@
 
 use tauri::Manager;
 
+mod clipboard;
+mod commands;
+mod comparison;
+mod estimator;
+mod jobs;
+mod menu;
+mod state;
+mod tray;
+mod warmup;
+
+use state::InitState;
+
 // Learn more about Tauri commands at https://tauri.app/v1/guides/features/command
 #[tauri::command]
-fn greet(name: &str) -> String {
-    format!("Hello, {}! This message comes from Rust!", name)
+fn greet(init: tauri::State<InitState>, name: &str) -> Result<String, String> {
+    init.ensure_ready()?;
+    Ok(format!("Hello, {}! This message comes from Rust!", name))
 }
 
 fn main() {
     println!("Starting Tauri application...");
-    
+
     tauri::Builder::default()
-        .invoke_handler(tauri::generate_handler![greet])
+        .menu(menu::build_menu())
+        .on_menu_event(menu::handle_menu_event)
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            commands::open_circuit_dialog,
+            commands::save_report,
+            commands::write_report_file,
+            estimator::start_estimation,
+            comparison::open_comparison_window,
+            comparison::get_window_by_label,
+            comparison::focus_or_create,
+            comparison::refresh_comparison_windows,
+            clipboard::copy_report_to_clipboard,
+            clipboard::export_to_active_app,
+            jobs::enqueue_estimation,
+            jobs::cancel_job,
+            jobs::list_jobs
+        ])
+        .manage(InitState::default())
+        .manage(comparison::ComparisonWindows::default())
+        .manage(jobs::JobQueue::default())
+        .system_tray(tray::build_tray())
+        .on_system_tray_event(|app, event| tray::handle_tray_event(app, event))
         .setup(|app| {
             println!("Tauri app setup complete");
-            
-            // Get the main window and ensure it's visible
-            let window = app.get_window("main").unwrap();
-            window.show().unwrap();
-            window.set_focus().unwrap();
-            
-            println!("Window should now be visible");
+
+            // The main window starts hidden (see tauri.conf.json) and stays
+            // that way until warmup finishes; the splashscreen covers the gap.
+            tauri::WindowBuilder::new(
+                app,
+                "splashscreen",
+                tauri::WindowUrl::App("splashscreen.html".into()),
+            )
+            .build()?;
+
+            tauri::async_runtime::spawn(warmup::run(app.handle()));
+
             Ok(())
         })
         .run(tauri::generate_context!())
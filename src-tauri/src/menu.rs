@@ -0,0 +1,91 @@
+// Native application menu and its event routing.
+
+use tauri::{CustomMenuItem, Menu, MenuItem, Submenu, WindowMenuEvent};
+
+/// Actions the application menu can trigger. The `id()` of each variant is
+/// what gets attached to the underlying `CustomMenuItem`, and `from_id` is
+/// the inverse used by the menu event handler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuAction {
+    OpenCircuit,
+    SaveReport,
+    ExportJson,
+    ExportCsv,
+}
+
+impl MenuAction {
+    fn id(self) -> &'static str {
+        match self {
+            MenuAction::OpenCircuit => "open_circuit",
+            MenuAction::SaveReport => "save_report",
+            MenuAction::ExportJson => "export_json",
+            MenuAction::ExportCsv => "export_csv",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "open_circuit" => Some(MenuAction::OpenCircuit),
+            "save_report" => Some(MenuAction::SaveReport),
+            "export_json" => Some(MenuAction::ExportJson),
+            "export_csv" => Some(MenuAction::ExportCsv),
+            _ => None,
+        }
+    }
+}
+
+/// Builds the native application menu shown in the title bar / menu bar.
+pub fn build_menu() -> Menu {
+    let file_menu = Submenu::new(
+        "File",
+        Menu::new()
+            .add_item(CustomMenuItem::new(MenuAction::OpenCircuit.id(), "Open Circuit"))
+            .add_item(CustomMenuItem::new(MenuAction::SaveReport.id(), "Save Estimation Report"))
+            .add_native_item(MenuItem::Separator)
+            .add_item(CustomMenuItem::new(MenuAction::ExportJson.id(), "Export as JSON"))
+            .add_item(CustomMenuItem::new(MenuAction::ExportCsv.id(), "Export as CSV"))
+            .add_native_item(MenuItem::Separator)
+            .add_native_item(MenuItem::Quit),
+    );
+
+    Menu::new().add_submenu(file_menu)
+}
+
+/// Dispatches a menu click to the matching command and forwards the action
+/// to the frontend so it can update its own state (e.g. open a modal).
+pub fn handle_menu_event(event: WindowMenuEvent) {
+    let Some(action) = MenuAction::from_id(event.menu_item_id()) else {
+        return;
+    };
+
+    let window = event.window().clone();
+    let _ = window.emit("menu-action", action.id());
+    match action {
+        MenuAction::OpenCircuit => crate::commands::open_circuit_dialog(window),
+        MenuAction::SaveReport => crate::commands::save_report(window),
+        MenuAction::ExportJson => crate::commands::export_report(window, "json"),
+        MenuAction::ExportCsv => crate::commands::export_report(window, "csv"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn id_from_id_roundtrips_for_every_action() {
+        for action in [
+            MenuAction::OpenCircuit,
+            MenuAction::SaveReport,
+            MenuAction::ExportJson,
+            MenuAction::ExportCsv,
+        ] {
+            assert_eq!(MenuAction::from_id(action.id()), Some(action));
+        }
+    }
+
+    #[test]
+    fn from_id_rejects_unknown_ids() {
+        assert_eq!(MenuAction::from_id("not_a_menu_action"), None);
+    }
+}
@@ -0,0 +1,29 @@
+// Tracks whether backend warmup has finished, so commands invoked too early
+// fail clearly instead of panicking on half-initialized state.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[derive(Default)]
+pub struct InitState {
+    ready: AtomicBool,
+}
+
+impl InitState {
+    pub fn is_ready(&self) -> bool {
+        self.ready.load(Ordering::Acquire)
+    }
+
+    pub fn mark_ready(&self) {
+        self.ready.store(true, Ordering::Release);
+    }
+
+    /// Returns an error suitable for bubbling up from a `#[tauri::command]`
+    /// if warmup hasn't finished yet.
+    pub fn ensure_ready(&self) -> Result<(), String> {
+        if self.is_ready() {
+            Ok(())
+        } else {
+            Err("backend is still initializing, please wait".into())
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// System tray icon and its menu/event handling.
+
+use tauri::{
+    AppHandle, CustomMenuItem, Manager, SystemTray, SystemTrayEvent, SystemTrayMenu,
+    SystemTrayMenuItem,
+};
+
+use crate::jobs::JobQueue;
+
+const SHOW_DASHBOARD: &str = "show_dashboard";
+const RUNNING_JOBS: &str = "running_jobs";
+const CANCEL_ALL: &str = "cancel_all";
+const QUIT: &str = "quit";
+
+pub fn build_tray() -> SystemTray {
+    let menu = SystemTrayMenu::new()
+        .add_item(CustomMenuItem::new(SHOW_DASHBOARD, "Show Dashboard"))
+        .add_item(CustomMenuItem::new(RUNNING_JOBS, "Running Jobs: 0").disabled())
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new(CANCEL_ALL, "Cancel All"))
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new(QUIT, "Quit"));
+
+    SystemTray::new().with_menu(menu)
+}
+
+pub fn handle_tray_event(app: &AppHandle, event: SystemTrayEvent) {
+    let SystemTrayEvent::MenuItemClick { id, .. } = event else {
+        return;
+    };
+
+    match id.as_str() {
+        SHOW_DASHBOARD => {
+            if let Some(window) = app.get_window("main") {
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+        }
+        CANCEL_ALL => {
+            app.state::<JobQueue>()
+                .cancel_all();
+            update_tray_title(app, 0);
+        }
+        QUIT => {
+            app.exit(0);
+        }
+        _ => {}
+    }
+}
+
+/// Updates the tray tooltip and the disabled "Running Jobs: N" item to
+/// reflect how many jobs are still in flight.
+pub fn update_tray_title(app: &AppHandle, running: usize) {
+    let tray_handle = app.tray_handle();
+    let _ = tray_handle.set_tooltip(&format!("Orquestra QRE — {} job(s) running", running));
+    let _ = tray_handle
+        .get_item(RUNNING_JOBS)
+        .set_title(format!("Running Jobs: {}", running));
+}
+
+/// Posts a tray notification for a completed estimation job.
+pub fn notify_job_complete(app: &AppHandle, circuit_label: &str) {
+    use tauri::api::notification::Notification;
+
+    let _ = Notification::new(&app.config().tauri.bundle.identifier)
+        .title("Estimation complete")
+        .body(format!("Finished estimating \"{}\"", circuit_label))
+        .show();
+}